@@ -1,5 +1,6 @@
 use bevy_ecs::component::Component;
 use nalgebra::Vector2 as Vec2;
+use std::time::Instant;
 
 #[derive(Component, Clone)]
-pub struct Points { pub points: Vec<Vec2<f64>> }
\ No newline at end of file
+pub struct Points { pub points: Vec<Vec2<f64>>, pub timestamps: Vec<Instant> }
@@ -0,0 +1,6 @@
+use bevy_ecs::component::Component;
+
+/// Per-point stroke width, one entry per `Points` sample that produced it (stretched
+/// across the flattened `Spline` when rendered), so a velocity-modulated stroke survives re-renders.
+#[derive(Component, Clone, Default)]
+pub struct StrokeProfile { pub widths: Vec<f64> }
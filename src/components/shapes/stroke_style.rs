@@ -0,0 +1,48 @@
+use bevy_ecs::component::Component;
+
+/// Width and cap/join configuration used when outlining a spline's variable-width stroke.
+#[derive(Component, Clone, Copy)]
+pub struct StrokeStyle {
+    pub width: f64,
+    pub cap: StrokeCap,
+    pub join: StrokeJoin,
+    pub miter_limit: f64,
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        StrokeStyle { width: 2.0, cap: StrokeCap::Round, join: StrokeJoin::Round, miter_limit: 4.0 }
+    }
+}
+
+impl StrokeStyle {
+    pub fn cycle_cap(&mut self) {
+        self.cap = match self.cap {
+            StrokeCap::Butt => StrokeCap::Round,
+            StrokeCap::Round => StrokeCap::Square,
+            StrokeCap::Square => StrokeCap::Butt,
+        };
+    }
+
+    pub fn cycle_join(&mut self) {
+        self.join = match self.join {
+            StrokeJoin::Miter => StrokeJoin::Round,
+            StrokeJoin::Round => StrokeJoin::Bevel,
+            StrokeJoin::Bevel => StrokeJoin::Miter,
+        };
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum StrokeCap {
+    Butt,
+    Round,
+    Square,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum StrokeJoin {
+    Miter,
+    Round,
+    Bevel,
+}
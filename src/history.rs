@@ -0,0 +1,116 @@
+use bevy_ecs::prelude::*;
+use vello::peniko::Color;
+
+use crate::components::shapes::bezier::BezierCurve;
+use crate::components::shapes::spline::Spline;
+use crate::components::shapes::stroke_profile::StrokeProfile;
+use crate::components::shapes::stroke_style::StrokeStyle;
+
+// everything needed to respawn an identical spline entity, since despawning loses it
+#[derive(Clone)]
+pub struct SplineSnapshot {
+    bez_spline: Vec<BezierCurve>,
+    color: Color,
+    widths: Vec<f64>,
+    style: StrokeStyle,
+}
+
+// a reversible drawing operation, as recorded for undo/redo
+#[derive(Clone)]
+pub enum DrawCommand {
+    AddSpline(Entity),
+    RemoveSpline(SplineSnapshot),
+}
+
+// undo/redo stacks for drawing operations
+#[derive(Resource, Default)]
+pub struct DrawHistory {
+    undo_stack: Vec<DrawCommand>,
+    redo_stack: Vec<DrawCommand>,
+}
+
+impl DrawHistory {
+    /// Record that `entity` was just added by a completed stroke.
+    pub fn record_add(&mut self, entity: Entity) {
+        self.undo_stack.push(DrawCommand::AddSpline(entity));
+        self.redo_stack.clear();
+    }
+
+    /// Record that the spline captured by `snapshot` was just erased.
+    pub fn record_remove(&mut self, snapshot: SplineSnapshot) {
+        self.undo_stack.push(DrawCommand::RemoveSpline(snapshot));
+        self.redo_stack.clear();
+    }
+}
+
+/// Snapshot `entity` so the caller can hand it to `DrawHistory::record_remove`
+/// before despawning it (despawning otherwise loses the data needed to undo it).
+pub fn snapshot_entity(world: &World, entity: Entity) -> Option<SplineSnapshot> {
+    let spline = world.get::<Spline>(entity)?;
+    let profile = world.get::<StrokeProfile>(entity)?;
+    let style = world.get::<StrokeStyle>(entity)?;
+
+    Some(SplineSnapshot {
+        bez_spline: spline.bez_spline.clone(),
+        color: spline.color,
+        widths: profile.widths.clone(),
+        style: *style,
+    })
+}
+
+// pop the undo stack and despawn/restore the affected entity, pushing its inverse onto the redo stack
+pub fn undo(world: &mut World) {
+    apply(world, |history| history.undo_stack.pop(), |history, inverse| history.redo_stack.push(inverse));
+}
+
+// pop the redo stack and replay it, pushing its inverse back onto the undo stack
+pub fn redo(world: &mut World) {
+    apply(world, |history| history.redo_stack.pop(), |history, inverse| history.undo_stack.push(inverse));
+}
+
+fn apply(
+    world: &mut World,
+    mut pop: impl FnMut(&mut DrawHistory) -> Option<DrawCommand>,
+    push_inverse: impl FnOnce(&mut DrawHistory, DrawCommand),
+) {
+    let Some(mut history) = world.remove_resource::<DrawHistory>() else {
+        return;
+    };
+
+    // a command can reference an entity another command already despawned (e.g. the
+    // eraser removed it out-of-band); skip stale entries instead of stopping there
+    while let Some(command) = pop(&mut history) {
+        if let Some(inverse) = apply_command(world, command) {
+            push_inverse(&mut history, inverse);
+            break;
+        }
+    }
+
+    world.insert_resource(history);
+}
+
+// apply `command`, returning its inverse so the caller can push it onto the other
+// stack (a restore inverts to a removal and vice versa)
+fn apply_command(world: &mut World, command: DrawCommand) -> Option<DrawCommand> {
+    match command {
+        DrawCommand::AddSpline(entity) => {
+            let snapshot = snapshot_entity(world, entity)?;
+            world.despawn(entity);
+            Some(DrawCommand::RemoveSpline(snapshot))
+        }
+        DrawCommand::RemoveSpline(snapshot) => {
+            let entity = spawn_snapshot(world, &snapshot);
+            Some(DrawCommand::AddSpline(entity))
+        }
+    }
+}
+
+fn spawn_snapshot(world: &mut World, snapshot: &SplineSnapshot) -> Entity {
+    world
+        .spawn((
+            Spline { bez_spline: snapshot.bez_spline.clone(), color: snapshot.color },
+            StrokeProfile { widths: snapshot.widths.clone() },
+            snapshot.style,
+        ))
+        .id()
+}
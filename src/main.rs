@@ -4,14 +4,17 @@ use bevy_ecs::prelude::*;
 use bevy_ecs::system::RunSystemOnce;
 use components::shapes::points::Points;
 use components::shapes::spline::Spline;
+use components::shapes::stroke_profile::StrokeProfile;
+use components::shapes::stroke_style::StrokeStyle;
 use systems::update_spline::sys_update_spline;
+use systems::update_stroke_profile::sys_update_stroke_profile;
 use std::num::NonZeroUsize;
 use std::time::Instant;
 use rand::Rng;
 use nalgebra::Vector2 as Vec2;
 use std::sync::Arc;
-use vello::kurbo::{Affine, BezPath, Point, Stroke};
-use vello::peniko::Color;
+use vello::kurbo::Affine;
+use vello::peniko::{Color, Fill};
 use vello::util::{RenderContext, RenderSurface};
 use vello::{AaConfig, Renderer, RendererOptions, Scene};
 use vello::wgpu;
@@ -20,12 +23,24 @@ use winit::dpi::LogicalSize;
 use winit::event::*;
 use winit::event_loop::{ActiveEventLoop, EventLoop};
 use winit::window::Window;
-use render::{ActiveRenderState, RenderState};
+use render::{variable_width_outline, ActiveRenderState, RenderState};
+use svg::{load_svg, save_svg};
+use math::hit_test::hits_spline;
+use history::DrawHistory;
+use std::path::Path;
+use winit::keyboard::ModifiersState;
 
 mod components;
 mod systems;
 mod math;
 mod render;
+mod svg;
+mod history;
+
+/// Default path a drawing is saved to / loaded from via the `s`/`o` shortcuts.
+const DRAWING_PATH: &str = "drawing.svg";
+/// Click-to-stroke distance, in pixels, within which the eraser despawns a spline.
+const ERASER_RADIUS: f64 = 8.0;
 
 struct ThinkApp<'s> {
     context: RenderContext,
@@ -36,9 +51,20 @@ struct ThinkApp<'s> {
     is_drawing: bool,
     start_draw_time: Option<Instant>,
     last_draw_time: Instant,
+    is_erasing: bool,
+    cursor_position: Vec2<f64>,
+    active_stroke_style: StrokeStyle,
+    modifiers: ModifiersState,
     world: World // ecs for everything. ui, shapes, etc
 }
 
+/// A fresh `World` with the resources this app depends on already inserted.
+fn fresh_world() -> World {
+    let mut world = World::default();
+    world.insert_resource(DrawHistory::default());
+    world
+}
+
 
 impl<'s> ApplicationHandler for ThinkApp<'s> {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
@@ -96,36 +122,59 @@ impl<'s> ApplicationHandler for ThinkApp<'s> {
                 match event.logical_key {
                     winit::keyboard::Key::Character(ch) => {
                         if ch == "r" || ch == "R" {
-                            self.world = World::default();
+                            self.world = fresh_world();
+                            render_state.window.request_redraw();
+                        } else if ch == "s" || ch == "S" {
+                            if let Err(e) = save_svg(&mut self.world, Path::new(DRAWING_PATH)) {
+                                eprintln!("Failed to save drawing to {DRAWING_PATH}: {e}");
+                            }
+                        } else if ch == "o" || ch == "O" {
+                            // load into a scratch world first so a failed load
+                            // (missing file, truncated path data) doesn't wipe the current drawing
+                            let mut loaded = fresh_world();
+                            match load_svg(&mut loaded, Path::new(DRAWING_PATH)) {
+                                Result::Ok(()) => {
+                                    self.world = loaded;
+                                    render_state.window.request_redraw();
+                                }
+                                Err(e) => eprintln!("Failed to load drawing from {DRAWING_PATH}: {e}"),
+                            }
+                        } else if ch == "e" || ch == "E" {
+                            self.is_erasing = !self.is_erasing;
+                        } else if ch == "c" || ch == "C" {
+                            self.active_stroke_style.cycle_cap();
+                        } else if ch == "j" || ch == "J" {
+                            self.active_stroke_style.cycle_join();
+                        } else if (ch == "z" || ch == "Z") && self.modifiers.control_key() {
+                            if self.modifiers.shift_key() {
+                                self.world.run_system_once(history::redo);
+                            } else {
+                                self.world.run_system_once(history::undo);
+                            }
+                            render_state.window.request_redraw();
+                        } else if (ch == "y" || ch == "Y") && self.modifiers.control_key() {
+                            self.world.run_system_once(history::redo);
                             render_state.window.request_redraw();
                         }
                     }
                     _ => {}
                 }
             }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                self.modifiers = modifiers.state();
+            }
             WindowEvent::RedrawRequested => {
                 self.scene.reset();
 
-                let mut query = self.world.query::<&Spline>();
-                query.iter(&self.world).for_each(|spline| {
-                    let mut bez_path = BezPath::new();
-
-                    for (i, curve) in spline.bez_spline.iter().enumerate() {
-                        if i == 0 {
-                            bez_path.move_to(Point::new(curve.start.x, curve.start.y));
-                        }
-                        bez_path.curve_to(
-                            Point::new(curve.control1.x, curve.control1.y),
-                            Point::new(curve.control2.x, curve.control2.y),
-                            Point::new(curve.end.x, curve.end.y),
-                        );
-                    }
-                    self.scene.stroke(
-                        &Stroke::new(2.0), 
-                        Affine::IDENTITY, 
-                        &spline.color, 
-                        None, 
-                        &bez_path
+                let mut query = self.world.query::<(&Spline, &StrokeProfile, &StrokeStyle)>();
+                query.iter(&self.world).for_each(|(spline, profile, style)| {
+                    let outline = variable_width_outline(&spline.bez_spline, &profile.widths, style);
+                    self.scene.fill(
+                        Fill::NonZero,
+                        Affine::IDENTITY,
+                        &spline.color,
+                        None,
+                        &outline,
                     );
                 });
 
@@ -164,37 +213,62 @@ impl<'s> ApplicationHandler for ThinkApp<'s> {
                 state: ElementState::Pressed,
                 ..
             } => {
-                let mut rng = rand::thread_rng();
-                self.is_drawing = true;
-                self.points.clear();
-                self.world.spawn(
-                    (
-                            Spline { 
-                                bez_spline: Vec::new(), 
-                                color: Color::rgb8(
-                                    rng.gen_range(0..255), 
-                                    rng.gen_range(0..255), 
-                                    rng.gen_range(0..255)
-                                ) 
-                            }, 
-                            Points { points: Vec::new() }
-                        )
-                );
+                if self.is_erasing {
+                    let mut query = self.world.query::<(Entity, &Spline)>();
+                    let hit = query
+                        .iter(&self.world)
+                        .find(|(_, spline)| hits_spline(self.cursor_position, &spline.bez_spline, ERASER_RADIUS))
+                        .map(|(entity, _)| entity);
+
+                    if let Some(entity) = hit {
+                        if let Some(snapshot) = history::snapshot_entity(&self.world, entity) {
+                            self.world.resource_mut::<DrawHistory>().record_remove(snapshot);
+                        }
+                        self.world.despawn(entity);
+                        render_state.window.request_redraw();
+                    }
+                } else {
+                    let mut rng = rand::thread_rng();
+                    self.is_drawing = true;
+                    self.points.clear();
+                    self.world.spawn(
+                        (
+                                Spline {
+                                    bez_spline: Vec::new(),
+                                    color: Color::rgb8(
+                                        rng.gen_range(0..255),
+                                        rng.gen_range(0..255),
+                                        rng.gen_range(0..255)
+                                    )
+                                },
+                                Points { points: Vec::new(), timestamps: Vec::new() },
+                                StrokeProfile::default(),
+                                self.active_stroke_style,
+                            )
+                    );
+                }
             }
             WindowEvent::MouseInput {
                 state: ElementState::Released,
                 ..
             } => {
-                self.is_drawing = false;
-                render_state.window.request_redraw();
-                // remove the points component from the entity once the user is done drawing
-                {
+                if self.is_drawing {
+                    self.is_drawing = false;
+                    render_state.window.request_redraw();
+
+                    // remove the points component from the entity once the user is done drawing
+                    // (use get_single_mut, not single_mut: a release with no drawn entity,
+                    // e.g. right after an eraser click, must not panic)
                     let mut query = self.world.query::<(Entity, &mut Points)>();
-                    let (entity, _) = query.single_mut(&mut self.world);
-                    self.world.entity_mut(entity).remove::<Points>();
+                    if let Result::Ok((entity, _)) = query.get_single_mut(&mut self.world) {
+                        self.world.entity_mut(entity).remove::<Points>();
+                        self.world.resource_mut::<DrawHistory>().record_add(entity);
+                    }
                 }
             }
             WindowEvent::CursorMoved { position, .. } => {
+                self.cursor_position = Vec2::new(position.x as f64, position.y as f64);
+
                 if self.is_drawing {
                     let now = Instant::now();
 
@@ -213,12 +287,14 @@ impl<'s> ApplicationHandler for ThinkApp<'s> {
                         match query.single_mut(&mut self.world) {
                             (mut points, _) => {
                                 points.points.push(Vec2::new(position.x as f64, position.y as f64));
+                                points.timestamps.push(now);
                             }
                         }
 
                         // decoupled logic for updating the spline
                         // should scale easier than the atrocity I had before
                         self.world.run_system_once(sys_update_spline);
+                        self.world.run_system_once(sys_update_stroke_profile);
 
                         render_state.window.request_redraw();
                     }
@@ -241,7 +317,11 @@ fn main() -> Result<()> {
         is_drawing: false,
         start_draw_time: None,
         last_draw_time: Instant::now(),
-        world: World::default(),
+        is_erasing: false,
+        cursor_position: Vec2::new(0.0, 0.0),
+        active_stroke_style: StrokeStyle::default(),
+        modifiers: ModifiersState::empty(),
+        world: fresh_world(),
     };
 
     let event_loop = EventLoop::new()?;
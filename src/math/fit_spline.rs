@@ -1,61 +1,227 @@
 use nalgebra::Vector2 as Vec2;
 use rayon::prelude::*;
 
-use super::fit_bez::fit_bezier_curve;
 use crate::components::shapes::bezier::BezierCurve;
 
-pub fn create_bezier_spline(points: &[Vec2<f64>], size: usize) -> Vec<BezierCurve> {
-    let mut spline_parts: Vec<_> = Vec::new();
-    let mut last_vec_i = 0;
-    let mut last_vec2 = points[0];
+// Newton-Raphson reparameterization passes attempted before a curve is split
+const MAX_REPARAM_ITERATIONS: usize = 4;
 
-    for i in 1..points.len() {
-        let vec2 = points[i];
-        if last_vec2.metric_distance(&vec2) > size as f64 {
-            let sub_points = points[last_vec_i..(i+1)].to_vec();
-            spline_parts.push(sub_points);
-            last_vec2 = vec2;
-            last_vec_i = i;
+// classic Schneider curve fit: one cubic across the whole span, tangents pinned to
+// the stroke's end directions, reparameterize a few times to save a bad fit, and
+// only split the point set (at the point of worst error) as a last resort
+pub fn create_bezier_spline(points: &[Vec2<f64>], tolerance: f64) -> Vec<BezierCurve> {
+    if points.len() < 2 {
+        return Vec::new();
+    }
+
+    let t_left = unit_tangent(points[1] - points[0]);
+    let t_right = unit_tangent(points[points.len() - 2] - points[points.len() - 1]);
+
+    fit_cubic(points, t_left, t_right, tolerance)
+}
+
+fn fit_cubic(
+    points: &[Vec2<f64>],
+    t_left: Vec2<f64>,
+    t_right: Vec2<f64>,
+    tolerance: f64,
+) -> Vec<BezierCurve> {
+    // Two points can only describe a straight segment; skip straight to the
+    // tangent-scaled fallback rather than solving a degenerate 2x2 system.
+    if points.len() == 2 {
+        let dist = points[0].metric_distance(&points[1]) / 3.0;
+        return vec![BezierCurve {
+            start: points[0],
+            control1: points[0] + t_left * dist,
+            control2: points[1] + t_right * dist,
+            end: points[1],
+        }];
+    }
+
+    let mut params = chord_length_parameterize(points);
+    let mut curve = generate_bezier(points, &params, t_left, t_right);
+    let (mut max_error, mut split_at) = max_squared_error(points, &params, &curve);
+
+    if max_error <= tolerance {
+        return vec![curve];
+    }
+
+    // Worth trying to reparameterize only when the fit is already in the ballpark.
+    if max_error <= tolerance * 4.0 {
+        for _ in 0..MAX_REPARAM_ITERATIONS {
+            reparameterize(points, &curve, &mut params);
+            curve = generate_bezier(points, &params, t_left, t_right);
+            let (error, idx) = max_squared_error(points, &params, &curve);
+            max_error = error;
+            split_at = idx;
+
+            if max_error <= tolerance {
+                return vec![curve];
+            }
         }
     }
 
-    if spline_parts.is_empty() {
-        spline_parts.push(points.to_vec());
+    let split_at = split_at.clamp(1, points.len() - 2);
+    let t_center = unit_tangent(points[split_at - 1] - points[split_at + 1]);
+    // both halves include points[split_at] so the two fitted curves share a joint,
+    // matching the reference algorithm, instead of leaving a visible gap between them
+    let left = &points[..=split_at];
+    let right = &points[split_at..];
+
+    let (mut left_curves, right_curves) = rayon::join(
+        || fit_cubic(left, t_left, t_center, tolerance),
+        || fit_cubic(right, -t_center, t_right, tolerance),
+    );
+
+    left_curves.extend(right_curves);
+    left_curves
+}
+
+fn unit_tangent(v: Vec2<f64>) -> Vec2<f64> {
+    let len = v.norm();
+    if len < f64::EPSILON {
+        Vec2::new(0.0, 0.0)
     } else {
-        let sub_points = points[last_vec_i..].to_vec();
-        spline_parts.push(sub_points);
+        v / len
     }
+}
 
-    let mut spline: Vec<BezierCurve> = spline_parts.into_par_iter()
-        .map(|sub_points| fit_bezier_curve(&sub_points))
-        .collect();
+fn chord_length_parameterize(points: &[Vec2<f64>]) -> Vec<f64> {
+    let mut u = vec![0.0; points.len()];
 
-    if spline.len() > 1 {
-        smooth_spline(&mut spline);
+    for i in 1..points.len() {
+        u[i] = u[i - 1] + points[i].metric_distance(&points[i - 1]);
+    }
+
+    let total = *u.last().unwrap();
+    if total > 0.0 {
+        for t in u.iter_mut() {
+            *t /= total;
+        }
     }
 
-    spline
+    u
 }
 
-fn smooth_spline(spline: &mut Vec<BezierCurve>) {
-    if spline.len() <= 1 {
-        return;
+// start/end pinned to the data, interior control points constrained to lie along
+// t_left/t_right, solving the 2x2 system for the tangent magnitudes alpha_l/alpha_r
+fn generate_bezier(
+    points: &[Vec2<f64>],
+    params: &[f64],
+    t_left: Vec2<f64>,
+    t_right: Vec2<f64>,
+) -> BezierCurve {
+    let first = points[0];
+    let last = *points.last().unwrap();
+
+    let mut c = [[0.0_f64; 2]; 2];
+    let mut x = [0.0_f64; 2];
+
+    for (point, &u) in points.iter().zip(params.iter()) {
+        let b = bernstein_basis(u);
+        let a_left = t_left * b[1];
+        let a_right = t_right * b[2];
+
+        c[0][0] += a_left.dot(&a_left);
+        c[0][1] += a_left.dot(&a_right);
+        c[1][0] = c[0][1];
+        c[1][1] += a_right.dot(&a_right);
+
+        let endpoint_term = first * (b[0] + b[1]) + last * (b[2] + b[3]);
+        let rhs = point - endpoint_term;
+
+        x[0] += rhs.dot(&a_left);
+        x[1] += rhs.dot(&a_right);
     }
 
-    // First pass: compute adjustments
-    let adjustments: Vec<_> = spline.windows(2)
-        .map(|window| {
-            let prev = &window[0];
-            let curr = &window[1];
-            let prev_tangent = prev.end - prev.control2;
-            (curr.start - prev.end, curr.start + prev_tangent - curr.control1)
-        })
-        .collect();
+    let det_c0_c1 = c[0][0] * c[1][1] - c[1][0] * c[0][1];
+    let det_c0_x = c[0][0] * x[1] - c[1][0] * x[0];
+    let det_x_c1 = x[0] * c[1][1] - x[1] * c[0][1];
+
+    let (alpha_l, alpha_r) = if det_c0_c1.abs() < 1e-12 {
+        (0.0, 0.0)
+    } else {
+        (det_x_c1 / det_c0_c1, det_c0_x / det_c0_c1)
+    };
 
-    // Second pass: apply adjustments in parallel
-    spline.par_iter_mut().skip(1).zip(adjustments.par_iter())
-        .for_each(|(curve, &(start_adj, control1_adj))| {
-            curve.start += start_adj;
-            curve.control1 += control1_adj;
-        });
-}
\ No newline at end of file
+    // Negative or vanishingly small tangent magnitudes mean the 2x2 system is
+    // unreliable (near-collinear points); fall back to a chord-length estimate.
+    let chord_length = first.metric_distance(&last);
+    let min_alpha = 1e-6 * chord_length;
+
+    let (alpha_l, alpha_r) = if alpha_l < min_alpha || alpha_r < min_alpha {
+        let fallback = chord_length / 3.0;
+        (fallback, fallback)
+    } else {
+        (alpha_l, alpha_r)
+    };
+
+    BezierCurve {
+        start: first,
+        control1: first + t_left * alpha_l,
+        control2: last + t_right * alpha_r,
+        end: last,
+    }
+}
+
+fn bernstein_basis(u: f64) -> [f64; 4] {
+    let v = 1.0 - u;
+    [v * v * v, 3.0 * v * v * u, 3.0 * v * u * u, u * u * u]
+}
+
+fn evaluate(curve: &BezierCurve, u: f64) -> Vec2<f64> {
+    let b = bernstein_basis(u);
+    curve.start * b[0] + curve.control1 * b[1] + curve.control2 * b[2] + curve.end * b[3]
+}
+
+fn evaluate_derivative(curve: &BezierCurve, u: f64) -> Vec2<f64> {
+    let v = 1.0 - u;
+    (curve.control1 - curve.start) * (3.0 * v * v)
+        + (curve.control2 - curve.control1) * (6.0 * v * u)
+        + (curve.end - curve.control2) * (3.0 * u * u)
+}
+
+fn evaluate_second_derivative(curve: &BezierCurve, u: f64) -> Vec2<f64> {
+    let v = 1.0 - u;
+    (curve.control2 - curve.control1 * 2.0 + curve.start) * (6.0 * v)
+        + (curve.end - curve.control2 * 2.0 + curve.control1) * (6.0 * u)
+}
+
+fn max_squared_error(points: &[Vec2<f64>], params: &[f64], curve: &BezierCurve) -> (f64, usize) {
+    let mut max_dist = 0.0;
+    let mut worst_index = points.len() / 2;
+
+    for (i, (point, &u)) in points.iter().zip(params.iter()).enumerate() {
+        let dist = (point - evaluate(curve, u)).norm_squared();
+        if dist > max_dist {
+            max_dist = dist;
+            worst_index = i;
+        }
+    }
+
+    (max_dist, worst_index)
+}
+
+fn reparameterize(points: &[Vec2<f64>], curve: &BezierCurve, params: &mut [f64]) {
+    points
+        .par_iter()
+        .zip(params.par_iter_mut())
+        .for_each(|(point, u)| *u = newton_raphson_root_find(curve, *point, *u));
+}
+
+// one Newton-Raphson step refining u against the curve, per Schneider's
+// t -= f(t)*f'(t) / (f'(t)^2 + f(t)*f''(t))
+fn newton_raphson_root_find(curve: &BezierCurve, point: Vec2<f64>, u: f64) -> f64 {
+    let diff = evaluate(curve, u) - point;
+    let q1 = evaluate_derivative(curve, u);
+    let q2 = evaluate_second_derivative(curve, u);
+
+    let numerator = diff.dot(&q1);
+    let denominator = q1.dot(&q1) + diff.dot(&q2);
+
+    if denominator.abs() < 1e-12 {
+        u
+    } else {
+        (u - numerator / denominator).clamp(0.0, 1.0)
+    }
+}
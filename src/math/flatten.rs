@@ -0,0 +1,78 @@
+use nalgebra::Vector2 as Vec2;
+
+use crate::components::shapes::bezier::BezierCurve;
+
+// Recursion cap for flatten_into, so a pathological imported curve (e.g. from a
+// hand-edited SVG) can't blow the stack chasing an unreachable flatness target.
+const MAX_SUBDIVIDE_DEPTH: u32 = 16;
+
+// recursive de Casteljau subdivision down to `tolerance`
+pub fn flatten(curve: &BezierCurve, tolerance: f64) -> Vec<Vec2<f64>> {
+    let mut points = vec![curve.start];
+    flatten_into(curve, tolerance, 0, &mut points);
+    points
+}
+
+// Flatten every curve of a spline into one continuous polyline, dropping the
+// duplicate join point between consecutive curves.
+pub fn flatten_spline(curves: &[BezierCurve], tolerance: f64) -> Vec<Vec2<f64>> {
+    let mut points = Vec::new();
+
+    for (i, curve) in curves.iter().enumerate() {
+        let mut curve_points = flatten(curve, tolerance);
+        if i > 0 {
+            curve_points.remove(0);
+        }
+        points.extend(curve_points);
+    }
+
+    points
+}
+
+fn flatten_into(curve: &BezierCurve, tolerance: f64, depth: u32, points: &mut Vec<Vec2<f64>>) {
+    if depth >= MAX_SUBDIVIDE_DEPTH || is_flat_enough(curve, tolerance) {
+        points.push(curve.end);
+        return;
+    }
+
+    let (left, right) = subdivide(curve);
+    flatten_into(&left, tolerance, depth + 1, points);
+    flatten_into(&right, tolerance, depth + 1, points);
+}
+
+fn is_flat_enough(curve: &BezierCurve, tolerance: f64) -> bool {
+    perpendicular_distance(curve.control1, curve.start, curve.end) <= tolerance
+        && perpendicular_distance(curve.control2, curve.start, curve.end) <= tolerance
+}
+
+fn perpendicular_distance(point: Vec2<f64>, line_start: Vec2<f64>, line_end: Vec2<f64>) -> f64 {
+    let line = line_end - line_start;
+    let len = line.norm();
+
+    if len < f64::EPSILON {
+        return point.metric_distance(&line_start);
+    }
+
+    let to_point = point - line_start;
+    (line.x * to_point.y - line.y * to_point.x).abs() / len
+}
+
+fn subdivide(curve: &BezierCurve) -> (BezierCurve, BezierCurve) {
+    let p01 = midpoint(curve.start, curve.control1);
+    let p12 = midpoint(curve.control1, curve.control2);
+    let p23 = midpoint(curve.control2, curve.end);
+
+    let p012 = midpoint(p01, p12);
+    let p123 = midpoint(p12, p23);
+
+    let p0123 = midpoint(p012, p123);
+
+    (
+        BezierCurve { start: curve.start, control1: p01, control2: p012, end: p0123 },
+        BezierCurve { start: p0123, control1: p123, control2: p23, end: curve.end },
+    )
+}
+
+fn midpoint(a: Vec2<f64>, b: Vec2<f64>) -> Vec2<f64> {
+    (a + b) / 2.0
+}
@@ -0,0 +1,29 @@
+use nalgebra::Vector2 as Vec2;
+
+use super::flatten::flatten_spline;
+use crate::components::shapes::bezier::BezierCurve;
+
+// finer than the stroke itself so erasing feels precise
+const FLATTEN_TOLERANCE: f64 = 0.5;
+
+// true when point comes within radius of any flattened segment
+pub fn hits_spline(point: Vec2<f64>, curves: &[BezierCurve], radius: f64) -> bool {
+    let polyline = flatten_spline(curves, FLATTEN_TOLERANCE);
+
+    polyline
+        .windows(2)
+        .any(|segment| distance_to_segment(point, segment[0], segment[1]) <= radius)
+}
+
+fn distance_to_segment(point: Vec2<f64>, a: Vec2<f64>, b: Vec2<f64>) -> f64 {
+    let ab = b - a;
+    let len_sq = ab.dot(&ab);
+
+    if len_sq < f64::EPSILON {
+        return point.metric_distance(&a);
+    }
+
+    let t = ((point - a).dot(&ab) / len_sq).clamp(0.0, 1.0);
+    let closest = a + ab * t;
+    point.metric_distance(&closest)
+}
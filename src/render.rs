@@ -1,8 +1,13 @@
+use std::f64::consts::PI;
 use std::sync::Arc;
 
+use vello::kurbo::{BezPath, Point};
 use vello::util::RenderSurface;
 use winit::window::Window;
 
+use crate::components::shapes::bezier::BezierCurve;
+use crate::components::shapes::stroke_style::{StrokeCap, StrokeJoin, StrokeStyle};
+
 // Simple struct to hold the state of the renderer
 pub struct ActiveRenderState<'s> {
     pub surface: RenderSurface<'s>,
@@ -12,4 +17,203 @@ pub struct ActiveRenderState<'s> {
 pub enum RenderState<'s> {
     Active(ActiveRenderState<'s>),
     Suspended(Option<Arc<Window>>),
+}
+
+/// Samples taken per curve when flattening for the variable-width outline below.
+const SAMPLES_PER_CURVE: usize = 16;
+
+/// Build a filled outline polygon for a calligraphic stroke: flatten `curves` into
+/// a polyline, then offset each sample by `±width/2` along its local normal (per
+/// `style.join`) to get left/right boundary point lists, capped per `style.cap`
+/// and joined into a single closed `BezPath`.
+pub fn variable_width_outline(curves: &[BezierCurve], widths: &[f64], style: &StrokeStyle) -> BezPath {
+    let polyline = sample_polyline(curves);
+    let mut path = BezPath::new();
+
+    if polyline.len() < 2 || widths.is_empty() {
+        return path;
+    }
+
+    let mut left = Vec::with_capacity(polyline.len());
+    let mut right = Vec::with_capacity(polyline.len());
+
+    for (i, &point) in polyline.iter().enumerate() {
+        let (nx, ny) = vertex_normal(&polyline, i, style.join, style.miter_limit);
+        let half = sample_width(widths, i, polyline.len()) / 2.0;
+
+        left.push(Point::new(point.x + nx * half, point.y + ny * half));
+        right.push(Point::new(point.x - nx * half, point.y - ny * half));
+    }
+
+    let last = polyline.len() - 1;
+    let end_half = sample_width(widths, last, polyline.len()) / 2.0;
+    let start_half = sample_width(widths, 0, polyline.len()) / 2.0;
+    let end_outward = tangent(&polyline, last - 1, last);
+    let start_outward = tangent(&polyline, 1, 0);
+
+    path.move_to(left[0]);
+    for &p in &left[1..] {
+        path.line_to(p);
+    }
+    append_cap(&mut path, polyline[last], left[last], right[last], end_outward, end_half, style.cap);
+    for &p in right[..last].iter().rev() {
+        path.line_to(p);
+    }
+    append_cap(&mut path, polyline[0], right[0], left[0], start_outward, start_half, style.cap);
+    path.close_path();
+
+    path
+}
+
+/// Line from `points[from]` to `points[to]`, normalized; used as the outward
+/// direction a square/round cap extends past the last sample of a stroke end.
+fn tangent(points: &[Point], from: usize, to: usize) -> (f64, f64) {
+    let a = points[from];
+    let b = points[to];
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    let len = (dx * dx + dy * dy).sqrt();
+
+    if len < f64::EPSILON {
+        (0.0, 0.0)
+    } else {
+        (dx / len, dy / len)
+    }
+}
+
+/// Extend the outline from `from` to `to` (both already offset by `radius` from
+/// `center`) according to `cap`: a flat edge for `Butt`, a squared-off extension
+/// for `Square`, or an arced sweep through the `outward` side for `Round`.
+fn append_cap(path: &mut BezPath, center: Point, from: Point, to: Point, outward: (f64, f64), radius: f64, cap: StrokeCap) {
+    match cap {
+        StrokeCap::Butt => {
+            path.line_to(to);
+        }
+        StrokeCap::Square => {
+            path.line_to(Point::new(from.x + outward.0 * radius, from.y + outward.1 * radius));
+            path.line_to(Point::new(to.x + outward.0 * radius, to.y + outward.1 * radius));
+            path.line_to(to);
+        }
+        StrokeCap::Round => {
+            const CAP_SEGMENTS: usize = 8;
+
+            let start_angle = (from.y - center.y).atan2(from.x - center.x);
+            let end_angle = (to.y - center.y).atan2(to.x - center.x);
+            let sweep = outward_sweep(start_angle, end_angle, outward);
+
+            for step in 1..CAP_SEGMENTS {
+                let angle = start_angle + sweep * (step as f64 / CAP_SEGMENTS as f64);
+                path.line_to(Point::new(center.x + radius * angle.cos(), center.y + radius * angle.sin()));
+            }
+            path.line_to(to);
+        }
+    }
+}
+
+/// The signed angular sweep from `start_angle` to `end_angle` that bulges through
+/// the `outward` side rather than back through the stroke body.
+fn outward_sweep(start_angle: f64, end_angle: f64, outward: (f64, f64)) -> f64 {
+    let mut sweep = end_angle - start_angle;
+    while sweep > PI {
+        sweep -= 2.0 * PI;
+    }
+    while sweep < -PI {
+        sweep += 2.0 * PI;
+    }
+
+    let mid_angle = start_angle + sweep / 2.0;
+    let faces_outward = mid_angle.cos() * outward.0 + mid_angle.sin() * outward.1 >= 0.0;
+
+    if faces_outward {
+        sweep
+    } else if sweep >= 0.0 {
+        sweep - 2.0 * PI
+    } else {
+        sweep + 2.0 * PI
+    }
+}
+
+fn sample_polyline(curves: &[BezierCurve]) -> Vec<Point> {
+    let mut points = Vec::new();
+
+    for (i, curve) in curves.iter().enumerate() {
+        if i == 0 {
+            points.push(Point::new(curve.start.x, curve.start.y));
+        }
+        for step in 1..=SAMPLES_PER_CURVE {
+            let t = step as f64 / SAMPLES_PER_CURVE as f64;
+            points.push(evaluate_cubic(curve, t));
+        }
+    }
+
+    points
+}
+
+fn evaluate_cubic(curve: &BezierCurve, t: f64) -> Point {
+    let mt = 1.0 - t;
+    let x = mt.powi(3) * curve.start.x
+        + 3.0 * mt.powi(2) * t * curve.control1.x
+        + 3.0 * mt * t.powi(2) * curve.control2.x
+        + t.powi(3) * curve.end.x;
+    let y = mt.powi(3) * curve.start.y
+        + 3.0 * mt.powi(2) * t * curve.control1.y
+        + 3.0 * mt * t.powi(2) * curve.control2.y
+        + t.powi(3) * curve.end.y;
+    Point::new(x, y)
+}
+
+/// The offset direction used at sample `i`: the averaged (smooth) normal for
+/// `Round`, the incoming segment's normal for `Bevel` (a faceted cut at sharp
+/// turns), or that same bisector scaled up to the true miter length for `Miter`
+/// (falling back to the bevel normal past `miter_limit`).
+fn vertex_normal(points: &[Point], i: usize, join: StrokeJoin, miter_limit: f64) -> (f64, f64) {
+    // the first sample has no incoming segment, so it takes the outgoing one's normal
+    let n_in = if i == 0 { segment_normal(points, 0, 1) } else { segment_normal(points, i - 1, i) };
+
+    match join {
+        StrokeJoin::Bevel => n_in,
+        StrokeJoin::Round => {
+            let n_out = segment_normal(points, i, (i + 1).min(points.len() - 1));
+            normalize(n_in.0 + n_out.0, n_in.1 + n_out.1)
+        }
+        StrokeJoin::Miter => {
+            let n_out = segment_normal(points, i, (i + 1).min(points.len() - 1));
+            let bisector = normalize(n_in.0 + n_out.0, n_in.1 + n_out.1);
+            let cos_half_angle = n_in.0 * bisector.0 + n_in.1 * bisector.1;
+
+            if cos_half_angle < f64::EPSILON {
+                return n_in;
+            }
+
+            let scale = 1.0 / cos_half_angle;
+            if scale > miter_limit {
+                return n_in;
+            }
+            (bisector.0 * scale, bisector.1 * scale)
+        }
+    }
+}
+
+fn segment_normal(points: &[Point], from: usize, to: usize) -> (f64, f64) {
+    let (dx, dy) = tangent(points, from, to);
+    (-dy, dx)
+}
+
+fn normalize(x: f64, y: f64) -> (f64, f64) {
+    let len = (x * x + y * y).sqrt();
+    if len < f64::EPSILON {
+        (0.0, 0.0)
+    } else {
+        (x / len, y / len)
+    }
+}
+
+fn sample_width(widths: &[f64], i: usize, point_count: usize) -> f64 {
+    if widths.len() == point_count {
+        return widths[i];
+    }
+
+    let t = i as f64 / (point_count - 1).max(1) as f64;
+    let idx = ((t * (widths.len() - 1) as f64).round() as usize).min(widths.len() - 1);
+    widths[idx]
 }
\ No newline at end of file
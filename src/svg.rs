@@ -0,0 +1,239 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use bevy_ecs::prelude::*;
+use nalgebra::Vector2 as Vec2;
+use vello::peniko::Color;
+
+use crate::components::shapes::bezier::BezierCurve;
+use crate::components::shapes::spline::Spline;
+use crate::components::shapes::stroke_profile::StrokeProfile;
+use crate::components::shapes::stroke_style::StrokeStyle;
+
+/// Stroke width given to splines loaded from SVG, which carry no recorded pen velocity.
+const IMPORTED_STROKE_WIDTH: f64 = 2.0;
+
+/// Render every `Spline` in `world` to a single SVG document.
+pub fn export_svg(world: &mut World) -> String {
+    let mut body = String::new();
+
+    let mut query = world.query::<&Spline>();
+    for spline in query.iter(world) {
+        body.push_str(&spline_to_path_element(spline));
+        body.push('\n');
+    }
+
+    format!("<svg xmlns=\"http://www.w3.org/2000/svg\">\n{body}</svg>\n")
+}
+
+/// Write every `Spline` in `world` out to `path` as an SVG document.
+pub fn save_svg(world: &mut World, path: &Path) -> io::Result<()> {
+    fs::write(path, export_svg(world))
+}
+
+/// Parse the SVG document at `path` and spawn a `Spline` entity for each `<path>` element found.
+pub fn load_svg(world: &mut World, path: &Path) -> io::Result<()> {
+    let contents = fs::read_to_string(path)?;
+
+    for (d, stroke) in parse_path_elements(&contents) {
+        let bez_spline = parse_path_data(&d)?;
+        let color = stroke
+            .and_then(|hex| parse_hex_color(&hex))
+            .unwrap_or(Color::WHITE);
+
+        world.spawn((
+            Spline { bez_spline, color },
+            StrokeProfile { widths: vec![IMPORTED_STROKE_WIDTH] },
+            StrokeStyle { width: IMPORTED_STROKE_WIDTH, ..StrokeStyle::default() },
+        ));
+    }
+
+    Ok(())
+}
+
+fn spline_to_path_element(spline: &Spline) -> String {
+    format!(
+        "<path d=\"{}\" stroke=\"{}\" fill=\"none\"/>",
+        curve_path_data(&spline.bez_spline),
+        color_to_hex(spline.color),
+    )
+}
+
+fn curve_path_data(curves: &[BezierCurve]) -> String {
+    let mut d = String::new();
+
+    for (i, curve) in curves.iter().enumerate() {
+        if i == 0 {
+            d.push_str(&format!("M {} {} ", curve.start.x, curve.start.y));
+        }
+        d.push_str(&format!(
+            "C {} {} {} {} {} {} ",
+            curve.control1.x, curve.control1.y, curve.control2.x, curve.control2.y, curve.end.x, curve.end.y,
+        ));
+    }
+
+    d.trim_end().to_string()
+}
+
+fn color_to_hex(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+fn parse_hex_color(hex: &str) -> Option<Color> {
+    let hex = hex.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some(Color::rgb8(r, g, b))
+}
+
+/// Pull out the `d` and `stroke` attributes of every `<path .../>` element in `svg`.
+fn parse_path_elements(svg: &str) -> Vec<(String, Option<String>)> {
+    let mut elements = Vec::new();
+    let mut rest = svg;
+
+    while let Some(start) = rest.find("<path") {
+        let Some(tag_end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start..start + tag_end];
+
+        if let Some(d) = extract_attr(tag, "d") {
+            elements.push((d, extract_attr(tag, "stroke")));
+        }
+
+        rest = &rest[start + tag_end + 1..];
+    }
+
+    elements
+}
+
+// only accept `name="` where it starts an attribute (preceded by whitespace), so
+// e.g. `d="..."` doesn't match inside `id="..."`
+fn extract_attr(tag: &str, name: &str) -> Option<String> {
+    let needle = format!("{name}=\"");
+    let mut rest = tag;
+
+    while let Some(pos) = rest.find(&needle) {
+        let preceded_by_boundary = rest[..pos]
+            .chars()
+            .next_back()
+            .map_or(true, |ch| ch.is_whitespace());
+
+        let start = pos + needle.len();
+        let Some(end_offset) = rest[start..].find('"') else {
+            return None;
+        };
+
+        if preceded_by_boundary {
+            return Some(rest[start..start + end_offset].to_string());
+        }
+
+        rest = &rest[start + end_offset + 1..];
+    }
+
+    None
+}
+
+fn malformed_path_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "truncated or malformed SVG path data")
+}
+
+/// Walk an SVG path `d` string, handling `M`/`C`/`L`/`Z` in both absolute and
+/// relative form, promoting lines to degenerate (collinear-control-point) cubics.
+fn parse_path_data(d: &str) -> io::Result<Vec<BezierCurve>> {
+    let tokens = tokenize_path(d);
+    let mut curves = Vec::new();
+    let mut cursor = Vec2::new(0.0, 0.0);
+    let mut subpath_start = Vec2::new(0.0, 0.0);
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match tokens[i].as_str() {
+            "M" | "m" => {
+                let point = read_point(&tokens, i + 1)?;
+                cursor = if tokens[i] == "m" { cursor + point } else { point };
+                subpath_start = cursor;
+                i += 3;
+            }
+            "L" | "l" => {
+                let point = read_point(&tokens, i + 1)?;
+                let end = if tokens[i] == "l" { cursor + point } else { point };
+                curves.push(line_to_cubic(cursor, end));
+                cursor = end;
+                i += 3;
+            }
+            "C" | "c" => {
+                let relative = tokens[i] == "c";
+                let offset = if relative { cursor } else { Vec2::new(0.0, 0.0) };
+                let control1 = read_point(&tokens, i + 1)? + offset;
+                let control2 = read_point(&tokens, i + 3)? + offset;
+                let end = read_point(&tokens, i + 5)? + offset;
+
+                curves.push(BezierCurve { start: cursor, control1, control2, end });
+                cursor = end;
+                i += 7;
+            }
+            "Z" | "z" => {
+                curves.push(line_to_cubic(cursor, subpath_start));
+                cursor = subpath_start;
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    Ok(curves)
+}
+
+fn read_point(tokens: &[String], index: usize) -> io::Result<Vec2<f64>> {
+    let (x, y) = tokens
+        .get(index)
+        .zip(tokens.get(index + 1))
+        .ok_or_else(malformed_path_error)?;
+    Ok(Vec2::new(parse_f64(x), parse_f64(y)))
+}
+
+fn line_to_cubic(start: Vec2<f64>, end: Vec2<f64>) -> BezierCurve {
+    let control1 = start + (end - start) / 3.0;
+    let control2 = start + (end - start) * (2.0 / 3.0);
+    BezierCurve { start, control1, control2, end }
+}
+
+fn parse_f64(token: &str) -> f64 {
+    token.parse().unwrap_or(0.0)
+}
+
+fn tokenize_path(d: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+
+    for ch in d.chars() {
+        if ch.is_ascii_alphabetic() {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            tokens.push(ch.to_string());
+        } else if ch == ',' || ch.is_whitespace() {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+        } else if ch == '-' && !current.is_empty() {
+            tokens.push(std::mem::take(&mut current));
+            current.push(ch);
+        } else {
+            current.push(ch);
+        }
+    }
+
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
@@ -0,0 +1,47 @@
+use bevy_ecs::system::Query;
+
+use crate::components::shapes::points::Points;
+use crate::components::shapes::stroke_profile::StrokeProfile;
+use crate::components::shapes::stroke_style::StrokeStyle;
+
+/// Width range, as a fraction of `StrokeStyle::width`, the pen speed is mapped into.
+const MIN_WIDTH_FACTOR: f64 = 0.5;
+const MAX_WIDTH_FACTOR: f64 = 3.0;
+/// Pen speed (px/s) at or above which a stroke bottoms out at its minimum width.
+const REFERENCE_VELOCITY: f64 = 1500.0;
+
+// any entities that have a Points attached are in the process of being drawn/edited
+pub fn sys_update_stroke_profile(mut query: Query<(&mut StrokeProfile, &Points, &StrokeStyle)>) {
+    let (mut profile, points, style) = query.single_mut();
+    profile.widths = velocities(points)
+        .into_iter()
+        .map(|v| width_for_velocity(v, style.width))
+        .collect();
+}
+
+fn velocities(points: &Points) -> Vec<f64> {
+    let mut velocities = Vec::with_capacity(points.points.len());
+
+    for i in 0..points.points.len() {
+        if i == 0 {
+            velocities.push(0.0);
+            continue;
+        }
+
+        let distance = points.points[i].metric_distance(&points.points[i - 1]);
+        let dt = points.timestamps[i]
+            .duration_since(points.timestamps[i - 1])
+            .as_secs_f64();
+
+        velocities.push(if dt > 0.0 { distance / dt } else { 0.0 });
+    }
+
+    velocities
+}
+
+fn width_for_velocity(velocity: f64, base_width: f64) -> f64 {
+    let t = (velocity / REFERENCE_VELOCITY).clamp(0.0, 1.0);
+    let max_width = base_width * MAX_WIDTH_FACTOR;
+    let min_width = base_width * MIN_WIDTH_FACTOR;
+    max_width - t * (max_width - min_width)
+}